@@ -0,0 +1,211 @@
+//! Imports rows from a pre-SeekJob `app.db` (the old hard-coded
+//! `/Volumes/...` SQLite file) into the current app data dir's database.
+//!
+//! Migration is schema-agnostic: it discovers tables and columns the two
+//! databases have in common and copies rows, so re-running it (or running
+//! it against a DB that already has some of the old rows) is a no-op for
+//! anything already present. The legacy and current databases each assign
+//! their own autoincrementing integer primary keys, so those columns are
+//! never copied verbatim (a legacy id can collide with an unrelated
+//! existing row); SQLite assigns each migrated row a fresh id, and
+//! idempotency is instead checked by comparing the remaining shared
+//! columns.
+
+use rusqlite::Connection;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Serialize)]
+pub struct MigrationProgress {
+    pub table: String,
+    pub migrated_rows: usize,
+    pub total_rows_after: usize,
+}
+
+#[derive(Clone, Default, Serialize)]
+pub struct MigrationSummary {
+    pub tables_migrated: Vec<String>,
+    pub rows_migrated: usize,
+}
+
+/// Copies rows from `legacy_path` into `current_db_path`. Backs up
+/// `current_db_path` first and leaves `legacy_path` untouched.
+///
+/// `on_progress` is called once per migrated table so callers (the Tauri
+/// command, the headless `migrate` subcommand) can report progress however
+/// fits their context.
+pub fn migrate_legacy_db(
+    legacy_path: &Path,
+    current_db_path: &Path,
+    backend_is_running: bool,
+    mut on_progress: impl FnMut(&MigrationProgress),
+) -> Result<MigrationSummary, String> {
+    if backend_is_running {
+        return Err("Cannot migrate while the backend holds the database open; stop SeekJob first".to_string());
+    }
+
+    if !legacy_path.exists() {
+        return Err(format!("No legacy database found at {}", legacy_path.display()));
+    }
+
+    if !current_db_path.exists() {
+        return Err(format!(
+            "Current database {} does not exist yet; start SeekJob once before migrating",
+            current_db_path.display()
+        ));
+    }
+
+    backup_current_db(current_db_path)?;
+
+    let conn = Connection::open(current_db_path).map_err(|e| format!("Cannot open current database: {e}"))?;
+    let legacy_sql_path = legacy_path.to_string_lossy().replace('\'', "''");
+    conn.execute_batch(&format!("ATTACH DATABASE '{legacy_sql_path}' AS legacy"))
+        .map_err(|e| format!("Cannot attach legacy database: {e}"))?;
+
+    let result = run_migration(&conn, &mut on_progress);
+
+    let _ = conn.execute_batch("DETACH DATABASE legacy");
+
+    result
+}
+
+fn run_migration(conn: &Connection, on_progress: &mut impl FnMut(&MigrationProgress)) -> Result<MigrationSummary, String> {
+    check_schema_compatible(conn)?;
+
+    let mut summary = MigrationSummary::default();
+
+    for table in shared_table_names(conn)? {
+        let autoincrement_pk = integer_primary_key_columns(conn, "main", &table)?;
+        let columns: Vec<String> = shared_columns(conn, &table)?
+            .into_iter()
+            .filter(|c| !autoincrement_pk.contains(c))
+            .collect();
+        if columns.is_empty() {
+            continue;
+        }
+
+        let before = row_count(conn, "main", &table)?;
+        let column_list = columns
+            .iter()
+            .map(|c| format!("\"{c}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let match_clause = columns
+            .iter()
+            .map(|c| format!("main.\"{table}\".\"{c}\" IS legacy.\"{table}\".\"{c}\""))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let sql = format!(
+            "INSERT INTO main.\"{table}\" ({column_list}) \
+             SELECT {column_list} FROM legacy.\"{table}\" \
+             WHERE NOT EXISTS (SELECT 1 FROM main.\"{table}\" WHERE {match_clause})"
+        );
+        conn.execute(&sql, []).map_err(|e| format!("Migrating table '{table}' failed: {e}"))?;
+        let after = row_count(conn, "main", &table)?;
+        let migrated = after.saturating_sub(before);
+
+        let progress = MigrationProgress {
+            table: table.clone(),
+            migrated_rows: migrated,
+            total_rows_after: after,
+        };
+        on_progress(&progress);
+
+        summary.rows_migrated += migrated;
+        summary.tables_migrated.push(table);
+    }
+
+    Ok(summary)
+}
+
+fn backup_current_db(current_db_path: &Path) -> Result<(), String> {
+    let backup_path = current_db_path.with_extension("db.pre-migration.bak");
+    fs::copy(current_db_path, &backup_path)
+        .map(|_| ())
+        .map_err(|e| format!("Cannot back up current database to {}: {e}", backup_path.display()))
+}
+
+fn check_schema_compatible(conn: &Connection) -> Result<(), String> {
+    let legacy_version: i64 = conn
+        .query_row("PRAGMA legacy.user_version", [], |row| row.get(0))
+        .map_err(|e| format!("Cannot read legacy schema version: {e}"))?;
+    let current_version: i64 = conn
+        .query_row("PRAGMA main.user_version", [], |row| row.get(0))
+        .map_err(|e| format!("Cannot read current schema version: {e}"))?;
+
+    if legacy_version > current_version {
+        return Err(format!(
+            "Legacy database schema version ({legacy_version}) is newer than the current app ({current_version}); update SeekJob before migrating"
+        ));
+    }
+
+    Ok(())
+}
+
+fn shared_table_names(conn: &Connection) -> Result<Vec<String>, String> {
+    let legacy_tables = table_names(conn, "legacy")?;
+    let current_tables = table_names(conn, "main")?;
+    Ok(legacy_tables.into_iter().filter(|t| current_tables.contains(t)).collect())
+}
+
+fn table_names(conn: &Connection, schema: &str) -> Result<Vec<String>, String> {
+    let sql = format!("SELECT name FROM {schema}.sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'");
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("Cannot list tables in '{schema}': {e}"))?;
+    let rows = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(|e| format!("Cannot list tables in '{schema}': {e}"))?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Cannot list tables in '{schema}': {e}"))
+}
+
+fn shared_columns(conn: &Connection, table: &str) -> Result<Vec<String>, String> {
+    let legacy_columns = column_names(conn, "legacy", table)?;
+    let current_columns = column_names(conn, "main", table)?;
+    Ok(current_columns.into_iter().filter(|c| legacy_columns.contains(c)).collect())
+}
+
+/// Columns that are `INTEGER PRIMARY KEY` (SQLite rowid aliases). These are
+/// independently autoincremented in each database, so copying them verbatim
+/// would let a migrated row collide with an unrelated existing row that has
+/// the same id; excluding them lets SQLite assign a fresh id. Columns of a
+/// multi-column primary key are not rowid aliases and are not returned.
+fn integer_primary_key_columns(conn: &Connection, schema: &str, table: &str) -> Result<Vec<String>, String> {
+    let sql = format!("PRAGMA {schema}.table_info(\"{table}\")");
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("Cannot read columns of '{table}': {e}"))?;
+    let rows = stmt
+        .query_map([], |row| {
+            let name: String = row.get(1)?;
+            let col_type: String = row.get(2)?;
+            let pk: i64 = row.get(5)?;
+            Ok((name, col_type, pk))
+        })
+        .map_err(|e| format!("Cannot read columns of '{table}': {e}"))?;
+
+    let mut pk_columns = Vec::new();
+    for row in rows {
+        let (name, col_type, pk) = row.map_err(|e| format!("Cannot read columns of '{table}': {e}"))?;
+        if pk > 0 {
+            pk_columns.push((name, col_type));
+        }
+    }
+    match pk_columns.as_slice() {
+        [(name, col_type)] if col_type.eq_ignore_ascii_case("integer") => Ok(vec![name.clone()]),
+        _ => Ok(Vec::new()),
+    }
+}
+
+fn column_names(conn: &Connection, schema: &str, table: &str) -> Result<Vec<String>, String> {
+    let sql = format!("PRAGMA {schema}.table_info(\"{table}\")");
+    let mut stmt = conn.prepare(&sql).map_err(|e| format!("Cannot read columns of '{table}': {e}"))?;
+    let rows = stmt
+        .query_map([], |row| row.get::<_, String>(1))
+        .map_err(|e| format!("Cannot read columns of '{table}': {e}"))?;
+    rows.collect::<Result<Vec<_>, _>>().map_err(|e| format!("Cannot read columns of '{table}': {e}"))
+}
+
+fn row_count(conn: &Connection, schema: &str, table: &str) -> Result<usize, String> {
+    let sql = format!("SELECT COUNT(*) FROM {schema}.\"{table}\"");
+    conn.query_row(&sql, [], |row| row.get::<_, i64>(0))
+        .map(|count| count as usize)
+        .map_err(|e| format!("Cannot count rows in '{table}': {e}"))
+}
@@ -1,22 +1,35 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use serde::Serialize;
+mod legacy_migration;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
 use std::env;
 use std::fs::{self, OpenOptions};
 use std::net::TcpListener;
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
-use tauri::{AppHandle, Manager, RunEvent, State};
+use tauri::{AppHandle, Emitter, Manager, RunEvent, State};
 
 #[derive(Clone)]
 struct AppState {
-    api_base: String,
+    api_base: Arc<Mutex<String>>,
+    api_token: String,
     data_dir: PathBuf,
     logs_dir: PathBuf,
     child: Arc<Mutex<Option<Child>>>,
+    port: Arc<Mutex<u16>>,
+    config: BackendLaunchConfig,
+    /// Tells the supervisor thread to stand down instead of treating a
+    /// missing child as a crash — set while `migrate_legacy_db` has
+    /// intentionally stopped the backend to get an exclusive DB handle.
+    paused: Arc<AtomicBool>,
+    shutdown: Arc<AtomicBool>,
 }
 
 #[derive(Serialize)]
@@ -31,11 +44,110 @@ struct BackendCommand {
     args: Vec<String>,
 }
 
+/// Everything needed to (re-)spawn the backend child on a given port, kept
+/// around so the supervisor thread can restart it without an `AppHandle`.
+#[derive(Clone)]
+struct BackendLaunchConfig {
+    backend: BackendCommand,
+    data_dir: PathBuf,
+    logs_dir: PathBuf,
+    db_url: String,
+    legacy_path: String,
+    api_token: String,
+}
+
+impl BackendLaunchConfig {
+    fn spawn(&self, port: u16) -> Result<Child, String> {
+        let stdout_log = self.logs_dir.join("backend.stdout.log");
+        let stderr_log = self.logs_dir.join("backend.stderr.log");
+        rotate_log_if_needed(&stdout_log);
+        rotate_log_if_needed(&stderr_log);
+
+        let stdout_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(stdout_log)
+            .map_err(|e| format!("Cannot open backend stdout log: {e}"))?;
+        let stderr_file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(stderr_log)
+            .map_err(|e| format!("Cannot open backend stderr log: {e}"))?;
+
+        let mut cmd = Command::new(&self.backend.program);
+        if !self.backend.args.is_empty() {
+            cmd.args(self.backend.args.clone());
+        }
+
+        cmd.env("SEEKJOB_PORT", port.to_string())
+            .env("PORT", port.to_string())
+            .env("SEEKJOB_DATA_DIR", self.data_dir.to_string_lossy().to_string())
+            .env("DATABASE_URL", self.db_url.clone())
+            .env("SEEKJOB_LEGACY_DB_PATH", self.legacy_path.clone())
+            .env("SEEKJOB_API_TOKEN", self.api_token.clone())
+            .stdout(Stdio::from(stdout_file))
+            .stderr(Stdio::from(stderr_file))
+            .spawn()
+            .map_err(|e| format!("Cannot spawn backend process ({}): {e}", self.backend.program.display()))
+    }
+}
+
 const BACKEND_BIN_NAME: &str = "seekjob-backend";
 
+/// SeekJob launcher: runs the desktop shell by default, or runs without a
+/// window when `--no-desktop` or a subcommand is given (`query` drives the
+/// bundled backend; `migrate` works on the database files directly).
+#[derive(Parser)]
+#[command(name = "seekjob", about = "SeekJob desktop app and headless CLI")]
+struct Cli {
+    /// Start the backend without creating the Tauri window.
+    #[arg(long, global = true)]
+    no_desktop: bool,
+
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Run a LinkedIn job search against the backend and print the results.
+    Query {
+        /// Search terms to send to the backend.
+        query: String,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Tsv)]
+        format: OutputFormat,
+    },
+    /// Import rows from a legacy `app.db` into the current SeekJob database.
+    Migrate {
+        /// Path to the legacy database; defaults to SEEKJOB_LEGACY_DB_PATH / the old hard-coded paths.
+        #[arg(long)]
+        legacy_db_path: Option<String>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Tsv,
+    Json,
+    Csv,
+}
+
+#[derive(Deserialize, Serialize)]
+struct SearchRow {
+    title: String,
+    company: String,
+    location: String,
+    url: String,
+}
+
 #[tauri::command]
 fn get_api_base(state: State<'_, AppState>) -> String {
-    state.api_base.clone()
+    state.api_base.lock().map(|base| base.clone()).unwrap_or_default()
+}
+
+#[tauri::command]
+fn get_api_token(state: State<'_, AppState>) -> String {
+    state.api_token.clone()
 }
 
 #[tauri::command]
@@ -46,27 +158,99 @@ fn get_app_paths(state: State<'_, AppState>) -> AppPaths {
     }
 }
 
-#[tauri::command]
-fn open_in_chrome(url: String) -> Result<(), String> {
-    let chrome_status = Command::new("open")
-        .args(["-a", "Google Chrome", &url])
-        .status()
-        .map_err(|e| format!("Failed to execute 'open -a Google Chrome': {e}"))?;
+const PREFERRED_BROWSER_FILE: &str = "preferred_browser.txt";
 
-    if chrome_status.success() {
+fn read_preferred_browser(data_dir: &Path) -> Option<String> {
+    fs::read_to_string(data_dir.join(PREFERRED_BROWSER_FILE))
+        .ok()
+        .map(|raw| raw.trim().to_string())
+        .filter(|name| !name.is_empty())
+}
+
+fn write_preferred_browser(data_dir: &Path, browser: &str) -> Result<(), String> {
+    fs::write(data_dir.join(PREFERRED_BROWSER_FILE), browser)
+        .map_err(|e| format!("Cannot persist preferred browser: {e}"))
+}
+
+fn command_succeeds(program: &str, args: &[&str]) -> bool {
+    Command::new(program).args(args).status().map(|status| status.success()).unwrap_or(false)
+}
+
+/// Spawns a browser binary without waiting for it to exit. Unlike `open`
+/// (macOS) or `cmd /C start` (Windows), a raw browser executable does not
+/// daemonize itself, so calling `.status()` on one blocks until the user
+/// closes every window of their whole browsing session.
+fn launch_detached(program: &str, args: &[&str]) -> bool {
+    Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .is_ok()
+}
+
+#[cfg(target_os = "macos")]
+fn launch_browser(url: &str, browser: Option<&str>) -> Result<(), String> {
+    if let Some(browser) = browser {
+        if command_succeeds("open", &["-a", browser, url]) {
+            return Ok(());
+        }
+    }
+    if command_succeeds("open", &["-a", "Google Chrome", url]) {
+        return Ok(());
+    }
+    if command_succeeds("open", &[url]) {
         return Ok(());
     }
+    Err("Could not open URL in the preferred, Chrome, or default browser".to_string())
+}
 
-    let fallback_status = Command::new("open")
-        .arg(&url)
-        .status()
-        .map_err(|e| format!("Failed to open URL with default browser: {e}"))?;
+#[cfg(target_os = "windows")]
+fn launch_browser(url: &str, browser: Option<&str>) -> Result<(), String> {
+    if let Some(browser) = browser {
+        if command_succeeds("cmd", &["/C", "start", "", browser, url]) {
+            return Ok(());
+        }
+    }
+    if command_succeeds("cmd", &["/C", "start", "", "chrome", url]) {
+        return Ok(());
+    }
+    if command_succeeds("cmd", &["/C", "start", "", url]) {
+        return Ok(());
+    }
+    Err("Could not open URL in the preferred, Chrome, or default browser".to_string())
+}
 
-    if fallback_status.success() {
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+fn launch_browser(url: &str, browser: Option<&str>) -> Result<(), String> {
+    if let Some(browser) = browser {
+        if launch_detached(browser, &[url]) {
+            return Ok(());
+        }
+    }
+    if command_succeeds("xdg-open", &[url]) {
         return Ok(());
     }
+    for candidate in ["google-chrome", "chromium"] {
+        if launch_detached(candidate, &[url]) {
+            return Ok(());
+        }
+    }
+    Err("Could not open URL with xdg-open or any known Chrome/Chromium binary".to_string())
+}
+
+#[tauri::command]
+fn open_in_browser(url: String, browser: Option<String>, state: State<'_, AppState>) -> Result<(), String> {
+    let chosen = match browser {
+        Some(browser) => {
+            let _ = write_preferred_browser(&state.data_dir, &browser);
+            Some(browser)
+        }
+        None => read_preferred_browser(&state.data_dir),
+    };
 
-    Err("Could not open URL in Chrome or fallback browser".to_string())
+    launch_browser(&url, chosen.as_deref())
 }
 
 fn reserve_port() -> Result<u16, String> {
@@ -79,7 +263,67 @@ fn reserve_port() -> Result<u16, String> {
     Ok(port)
 }
 
-fn resolve_backend_command(app: &AppHandle) -> Result<BackendCommand, String> {
+fn port_is_free(port: u16) -> bool {
+    TcpListener::bind(("127.0.0.1", port)).is_ok()
+}
+
+fn generate_api_token() -> String {
+    let mut bytes = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+const DEFAULT_LOG_ROTATE_MAX_BYTES: u64 = 5 * 1024 * 1024;
+const DEFAULT_LOG_ROTATE_MAX_FILES: u32 = 5;
+
+fn log_rotate_max_bytes() -> u64 {
+    env::var("SEEKJOB_LOG_MAX_BYTES")
+        .ok()
+        .and_then(|raw| raw.parse().ok())
+        .unwrap_or(DEFAULT_LOG_ROTATE_MAX_BYTES)
+}
+
+fn log_rotate_max_files() -> u32 {
+    env::var("SEEKJOB_LOG_MAX_FILES")
+        .ok()
+        .and_then(|raw| raw.parse().ok())
+        .unwrap_or(DEFAULT_LOG_ROTATE_MAX_FILES)
+}
+
+/// Renames `backend.stdout.log` (etc.) to `.1`, shifting older numbered
+/// files up and dropping anything past the retention count, when the file
+/// has grown past the configured threshold. Called right before the log is
+/// (re-)opened for a fresh backend launch.
+fn rotate_log_if_needed(path: &Path) {
+    let max_files = log_rotate_max_files();
+    if max_files == 0 {
+        return;
+    }
+
+    let size = match fs::metadata(path) {
+        Ok(meta) => meta.len(),
+        Err(_) => return,
+    };
+
+    if size <= log_rotate_max_bytes() {
+        return;
+    }
+
+    let file_name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let numbered = |n: u32| path.with_file_name(format!("{file_name}.{n}"));
+
+    let _ = fs::remove_file(numbered(max_files));
+    for n in (1..max_files).rev() {
+        let from = numbered(n);
+        if from.exists() {
+            let _ = fs::rename(&from, numbered(n + 1));
+        }
+    }
+
+    let _ = fs::rename(path, numbered(1));
+}
+
+fn resolve_backend_command(app: Option<&AppHandle>) -> Result<BackendCommand, String> {
     if let Ok(path) = std::env::var("SEEKJOB_BACKEND_BIN") {
         let candidate = PathBuf::from(path);
         if candidate.exists() {
@@ -106,10 +350,16 @@ fn resolve_backend_command(app: &AppHandle) -> Result<BackendCommand, String> {
         }
     }
 
-    let resource_dir = app
-        .path()
-        .resource_dir()
-        .map_err(|e| format!("Cannot resolve Tauri resources dir: {e}"))?;
+    let resource_dir = match app {
+        Some(app) => app
+            .path()
+            .resource_dir()
+            .map_err(|e| format!("Cannot resolve Tauri resources dir: {e}"))?,
+        None => env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|p| p.to_path_buf()))
+            .ok_or_else(|| "Cannot resolve directory of the running executable".to_string())?,
+    };
 
     let candidates = vec![
         resource_dir.join(BACKEND_BIN_NAME),
@@ -176,13 +426,13 @@ fn find_backend_recursive(dir: &Path, max_depth: usize) -> Option<PathBuf> {
     walk(dir, 0, max_depth)
 }
 
-fn wait_for_health(api_base: &str, max_wait: Duration) -> bool {
+fn wait_for_health(api_base: &str, token: &str, max_wait: Duration) -> bool {
     let mut elapsed = Duration::from_millis(0);
     let step = Duration::from_millis(300);
 
     while elapsed < max_wait {
         let url = format!("{api_base}/health");
-        if let Ok(resp) = ureq::get(&url).call() {
+        if let Ok(resp) = ureq::get(&url).set("Authorization", &format!("Bearer {token}")).call() {
             if resp.status() == 200 {
                 return true;
             }
@@ -195,7 +445,7 @@ fn wait_for_health(api_base: &str, max_wait: Duration) -> bool {
     false
 }
 
-fn resolve_seekjob_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+fn resolve_seekjob_data_dir(app: Option<&AppHandle>) -> Result<PathBuf, String> {
     if let Ok(raw) = env::var("SEEKJOB_DATA_DIR_OVERRIDE") {
         let trimmed = raw.trim();
         if !trimmed.is_empty() {
@@ -215,7 +465,8 @@ fn resolve_seekjob_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
         }
     }
 
-    app.path()
+    app.ok_or_else(|| "No Tauri app handle available and no data dir override or $HOME set".to_string())?
+        .path()
         .app_data_dir()
         .map_err(|e| format!("Cannot resolve app data dir: {e}"))
 }
@@ -241,7 +492,7 @@ fn resolve_legacy_db_path() -> String {
     String::new()
 }
 
-fn start_backend(app: &AppHandle) -> Result<AppState, String> {
+fn start_backend(app: Option<&AppHandle>) -> Result<AppState, String> {
     let data_dir = resolve_seekjob_data_dir(app)?;
     fs::create_dir_all(&data_dir).map_err(|e| format!("Cannot create app data dir: {e}"))?;
 
@@ -252,54 +503,260 @@ fn start_backend(app: &AppHandle) -> Result<AppState, String> {
     let api_base = format!("http://127.0.0.1:{port}/api");
     let db_path = data_dir.join("app.db");
     let db_url = format!("sqlite:///{}", db_path.to_string_lossy());
-
     let backend = resolve_backend_command(app)?;
-
-    let stdout_log = logs_dir.join("backend.stdout.log");
-    let stderr_log = logs_dir.join("backend.stderr.log");
-    let stdout_file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(stdout_log)
-        .map_err(|e| format!("Cannot open backend stdout log: {e}"))?;
-    let stderr_file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(stderr_log)
-        .map_err(|e| format!("Cannot open backend stderr log: {e}"))?;
-
     let legacy_path = resolve_legacy_db_path();
+    let api_token = generate_api_token();
 
-    let mut cmd = Command::new(&backend.program);
-    if !backend.args.is_empty() {
-        cmd.args(backend.args.clone());
-    }
+    let config = BackendLaunchConfig {
+        backend,
+        data_dir: data_dir.clone(),
+        logs_dir: logs_dir.clone(),
+        db_url,
+        legacy_path,
+        api_token: api_token.clone(),
+    };
 
-    let mut child = cmd
-        .env("SEEKJOB_PORT", port.to_string())
-        .env("PORT", port.to_string())
-        .env("SEEKJOB_DATA_DIR", data_dir.to_string_lossy().to_string())
-        .env("DATABASE_URL", db_url)
-        .env("SEEKJOB_LEGACY_DB_PATH", legacy_path)
-        .stdout(Stdio::from(stdout_file))
-        .stderr(Stdio::from(stderr_file))
-        .spawn()
-        .map_err(|e| format!("Cannot spawn backend process ({}): {e}", backend.program.display()))?;
+    let mut child = config.spawn(port)?;
 
-    if !wait_for_health(&api_base, Duration::from_secs(70)) {
+    if !wait_for_health(&api_base, &api_token, Duration::from_secs(70)) {
         let _ = child.kill();
         return Err("Backend process started but /api/health did not become ready in time".to_string());
     }
 
+    let api_base = Arc::new(Mutex::new(api_base));
+    let child_slot = Arc::new(Mutex::new(Some(child)));
+    let port_slot = Arc::new(Mutex::new(port));
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let paused = Arc::new(AtomicBool::new(false));
+
+    spawn_supervisor(
+        app.cloned(),
+        config.clone(),
+        api_base.clone(),
+        child_slot.clone(),
+        port_slot.clone(),
+        paused.clone(),
+        shutdown.clone(),
+    );
+
     Ok(AppState {
         api_base,
+        api_token,
         data_dir,
         logs_dir,
-        child: Arc::new(Mutex::new(Some(child))),
+        child: child_slot,
+        port: port_slot,
+        config,
+        paused,
+        shutdown,
     })
 }
 
+/// Watches the backend child in the background and restarts it with
+/// exponential backoff if it crashes or stops answering `/health`.
+fn spawn_supervisor(
+    app: Option<AppHandle>,
+    config: BackendLaunchConfig,
+    api_base: Arc<Mutex<String>>,
+    child_slot: Arc<Mutex<Option<Child>>>,
+    port_slot: Arc<Mutex<u16>>,
+    paused: Arc<AtomicBool>,
+    shutdown: Arc<AtomicBool>,
+) {
+    const POLL_INTERVAL: Duration = Duration::from_secs(5);
+    const MAX_RESTART_ATTEMPTS: u32 = 6;
+    const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+    const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+    thread::spawn(move || {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            if paused.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            let still_running = child_slot
+                .lock()
+                .ok()
+                .map(|mut guard| match guard.as_mut() {
+                    Some(child) => matches!(child.try_wait(), Ok(None)),
+                    None => false,
+                })
+                .unwrap_or(false);
+
+            let current_base = api_base.lock().map(|base| base.clone()).unwrap_or_default();
+            if still_running && wait_for_health(&current_base, &config.api_token, Duration::from_millis(800)) {
+                continue;
+            }
+
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            if let Some(app) = &app {
+                let _ = app.emit("backend-down", &current_base);
+            }
+
+            if let Ok(mut guard) = child_slot.lock() {
+                if let Some(mut child) = guard.take() {
+                    let _ = child.kill();
+                }
+            }
+
+            let mut backoff = INITIAL_BACKOFF;
+            let mut restarted = false;
+
+            for _ in 0..MAX_RESTART_ATTEMPTS {
+                thread::sleep(backoff);
+
+                if shutdown.load(Ordering::SeqCst) || paused.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                let current_port = port_slot.lock().map(|p| *p).unwrap_or(0);
+                let port = if port_is_free(current_port) {
+                    current_port
+                } else {
+                    match reserve_port() {
+                        Ok(p) => p,
+                        Err(_) => {
+                            backoff = (backoff * 2).min(MAX_BACKOFF);
+                            continue;
+                        }
+                    }
+                };
+
+                if let Ok(mut new_child) = config.spawn(port) {
+                    let new_base = format!("http://127.0.0.1:{port}/api");
+                    if wait_for_health(&new_base, &config.api_token, Duration::from_secs(30)) {
+                        // Recheck `paused` while holding the child lock: migrate_legacy_db
+                        // takes this same lock to claim exclusive ownership of the child,
+                        // so whichever of us locks first wins and the other backs off
+                        // instead of the two racing to reassign the child/api_base.
+                        let claimed = match child_slot.lock() {
+                            Ok(mut guard) if !paused.load(Ordering::SeqCst) => {
+                                *guard = Some(new_child);
+                                if let Ok(mut base) = api_base.lock() {
+                                    *base = new_base.clone();
+                                }
+                                if let Ok(mut slot) = port_slot.lock() {
+                                    *slot = port;
+                                }
+                                true
+                            }
+                            _ => {
+                                let _ = new_child.kill();
+                                false
+                            }
+                        };
+
+                        restarted = claimed;
+                        break;
+                    }
+                }
+
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+
+            if shutdown.load(Ordering::SeqCst) {
+                break;
+            }
+
+            if paused.load(Ordering::SeqCst) {
+                continue;
+            }
+
+            let final_base = api_base.lock().map(|base| base.clone()).unwrap_or_default();
+            if let Some(app) = &app {
+                if restarted {
+                    let _ = app.emit("backend-restarted", &final_base);
+                } else {
+                    let _ = app.emit("backend-down", &final_base);
+                }
+            }
+
+            if !restarted {
+                break;
+            }
+        }
+    });
+}
+
+fn resolve_legacy_db_path_with_override(override_path: Option<&str>) -> String {
+    if let Some(path) = override_path {
+        let trimmed = path.trim();
+        if !trimmed.is_empty() {
+            return trimmed.to_string();
+        }
+    }
+
+    resolve_legacy_db_path()
+}
+
+#[tauri::command]
+fn migrate_legacy_db(app: AppHandle, state: State<'_, AppState>) -> Result<legacy_migration::MigrationSummary, String> {
+    let legacy_path = resolve_legacy_db_path_with_override(None);
+    if legacy_path.is_empty() {
+        return Err("No legacy database found".to_string());
+    }
+
+    state.paused.store(true, Ordering::SeqCst);
+    let stopped_child = state.child.lock().map(|mut guard| guard.take()).unwrap_or(None);
+    let was_running = stopped_child.is_some();
+    if let Some(mut child) = stopped_child {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+
+    let result = legacy_migration::migrate_legacy_db(
+        Path::new(&legacy_path),
+        &state.data_dir.join("app.db"),
+        false,
+        |progress| {
+            let _ = app.emit("legacy-migration-progress", progress);
+        },
+    );
+
+    if was_running {
+        restart_backend_after_migration(&app, &state);
+    }
+    state.paused.store(false, Ordering::SeqCst);
+
+    result
+}
+
+/// Spawns a fresh backend on the same port after `migrate_legacy_db` stopped
+/// it for an exclusive DB handle, and swaps it back into `state` so the
+/// supervisor resumes watching a live child once it's unpaused.
+fn restart_backend_after_migration(app: &AppHandle, state: &AppState) {
+    let port = state.port.lock().map(|p| *p).unwrap_or(0);
+    let new_child = match state.config.spawn(port) {
+        Ok(child) => child,
+        Err(_) => return,
+    };
+
+    let new_base = format!("http://127.0.0.1:{port}/api");
+    if !wait_for_health(&new_base, &state.config.api_token, Duration::from_secs(30)) {
+        return;
+    }
+
+    if let Ok(mut guard) = state.child.lock() {
+        *guard = Some(new_child);
+    }
+    if let Ok(mut guard) = state.api_base.lock() {
+        *guard = new_base.clone();
+    }
+    let _ = app.emit("backend-restarted", &new_base);
+}
+
 fn stop_backend(state: &AppState) {
+    state.shutdown.store(true, Ordering::SeqCst);
+
     if let Ok(mut guard) = state.child.lock() {
         if let Some(child) = guard.as_mut() {
             let _ = child.kill();
@@ -308,28 +765,187 @@ fn stop_backend(state: &AppState) {
     }
 }
 
-fn main() {
+fn search_backend(api_base: &str, token: &str, query: &str) -> Result<Vec<SearchRow>, String> {
+    let url = format!("{api_base}/search");
+    let resp = ureq::get(&url)
+        .set("Authorization", &format!("Bearer {token}"))
+        .query("q", query)
+        .call()
+        .map_err(|e| format!("Search request failed: {e}"))?;
+
+    resp.into_json::<Vec<SearchRow>>()
+        .map_err(|e| format!("Could not parse search response: {e}"))
+}
+
+/// Quotes/escapes a single field for delimited output so values containing
+/// the separator, quotes, or newlines can't shift downstream columns.
+fn format_field(field: &str, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Csv => {
+            if field.contains(',') || field.contains('"') || field.contains('\n') || field.contains('\r') {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        }
+        OutputFormat::Tsv => field
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r"),
+        OutputFormat::Json => field.to_string(),
+    }
+}
+
+fn print_rows(rows: &[SearchRow], format: OutputFormat) {
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(rows).unwrap_or_else(|_| "[]".to_string()));
+        }
+        OutputFormat::Tsv | OutputFormat::Csv => {
+            let sep = if matches!(format, OutputFormat::Csv) { "," } else { "\t" };
+            for row in rows {
+                let fields = [&row.title, &row.company, &row.location, &row.url]
+                    .map(|field| format_field(field, format));
+                println!("{}", fields.join(sep));
+            }
+        }
+    }
+}
+
+/// Starts the backend without a Tauri app handle, runs the query, tears the
+/// backend back down, and returns the process exit code.
+fn run_query_command(query: &str, format: OutputFormat) -> i32 {
+    let state = match start_backend(None) {
+        Ok(state) => state,
+        Err(e) => {
+            eprintln!("Failed to start backend: {e}");
+            return 1;
+        }
+    };
+
+    let api_base = state.api_base.lock().map(|base| base.clone()).unwrap_or_default();
+    let exit_code = match search_backend(&api_base, &state.api_token, query) {
+        Ok(rows) => {
+            print_rows(&rows, format);
+            0
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    };
+
+    stop_backend(&state);
+    exit_code
+}
+
+/// Starts the backend with no desktop window and keeps it alive until the
+/// child process exits, mirroring the lifecycle `run_desktop_app` gives it.
+fn run_headless() -> i32 {
+    let state = match start_backend(None) {
+        Ok(state) => state,
+        Err(e) => {
+            eprintln!("Failed to start backend: {e}");
+            return 1;
+        }
+    };
+
+    let exit_code = loop {
+        let exited = state
+            .child
+            .lock()
+            .ok()
+            .and_then(|mut guard| guard.as_mut().and_then(|child| child.try_wait().ok()).flatten());
+
+        if let Some(status) = exited {
+            break if status.success() { 0 } else { 1 };
+        }
+
+        thread::sleep(Duration::from_millis(500));
+    };
+
+    stop_backend(&state);
+    exit_code
+}
+
+/// Runs the legacy `app.db` migration with no `AppHandle`, reporting
+/// progress to stdout instead of Tauri events, and returns the exit code.
+fn run_migrate_command(legacy_db_path_override: Option<&str>) -> i32 {
+    let legacy_path = resolve_legacy_db_path_with_override(legacy_db_path_override);
+    if legacy_path.is_empty() {
+        eprintln!("No legacy database found; pass --legacy-db-path or set SEEKJOB_LEGACY_DB_PATH");
+        return 1;
+    }
+
+    let data_dir = match resolve_seekjob_data_dir(None) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{e}");
+            return 1;
+        }
+    };
+
+    match legacy_migration::migrate_legacy_db(Path::new(&legacy_path), &data_dir.join("app.db"), false, |progress| {
+        println!("{}: migrated {} rows ({} total)", progress.table, progress.migrated_rows, progress.total_rows_after);
+    }) {
+        Ok(summary) => {
+            println!(
+                "Migration complete: {} rows across {} tables",
+                summary.rows_migrated,
+                summary.tables_migrated.len()
+            );
+            0
+        }
+        Err(e) => {
+            eprintln!("Migration failed: {e}");
+            1
+        }
+    }
+}
+
+fn run_desktop_app() {
     tauri::Builder::default()
         .setup(|app| {
-            let state = match start_backend(app.handle()) {
+            let state = match start_backend(Some(app.handle())) {
                 Ok(state) => state,
                 Err(e) => {
                     eprintln!("Desktop bootstrap failed: {e}");
-                    let data_dir = resolve_seekjob_data_dir(app.handle()).unwrap_or_else(|_| PathBuf::from("."));
+                    let data_dir =
+                        resolve_seekjob_data_dir(Some(app.handle())).unwrap_or_else(|_| PathBuf::from("."));
                     let logs_dir = data_dir.join("logs");
                     let _ = fs::create_dir_all(&logs_dir);
+                    let config = BackendLaunchConfig {
+                        backend: BackendCommand { program: PathBuf::new(), args: Vec::new() },
+                        data_dir: data_dir.clone(),
+                        logs_dir: logs_dir.clone(),
+                        db_url: String::new(),
+                        legacy_path: String::new(),
+                        api_token: String::new(),
+                    };
                     AppState {
-                        api_base: "http://127.0.0.1:0/api".to_string(),
+                        api_base: Arc::new(Mutex::new("http://127.0.0.1:0/api".to_string())),
+                        api_token: String::new(),
                         data_dir,
                         logs_dir,
                         child: Arc::new(Mutex::new(None)),
+                        port: Arc::new(Mutex::new(0)),
+                        config,
+                        paused: Arc::new(AtomicBool::new(false)),
+                        shutdown: Arc::new(AtomicBool::new(false)),
                     }
                 }
             };
             app.manage(state);
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![get_api_base, get_app_paths, open_in_chrome])
+        .invoke_handler(tauri::generate_handler![
+            get_api_base,
+            get_api_token,
+            get_app_paths,
+            open_in_browser,
+            migrate_legacy_db
+        ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(|app_handle, event| {
@@ -340,3 +956,21 @@ fn main() {
             }
         });
 }
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Commands::Query { query, format }) => std::process::exit(run_query_command(&query, format)),
+        Some(Commands::Migrate { legacy_db_path }) => {
+            std::process::exit(run_migrate_command(legacy_db_path.as_deref()))
+        }
+        None => {}
+    }
+
+    if cli.no_desktop {
+        std::process::exit(run_headless());
+    }
+
+    run_desktop_app();
+}